@@ -1,5 +1,6 @@
 mod numbers;
 mod result;
+mod slice;
 mod transmuter;
 
 use crate::result::FFIResult;
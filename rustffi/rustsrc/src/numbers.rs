@@ -1,3 +1,14 @@
+use std::ffi::{c_char, CStr};
+use std::str::FromStr;
+
+use crate::result::FFIResult;
+
+/// Number of fractional digits a cosmwasm `Decimal` carries.
+const DECIMAL_FRACTIONAL_DIGITS: usize = 18;
+
+/// `10^18`, the number of atomics in one whole unit of a cosmwasm `Decimal`.
+const DECIMAL_FRACTIONAL: u128 = 1_000_000_000_000_000_000;
+
 /// FFI-safe little endian u128 construction
 #[repr(C)]
 #[derive(Clone)]
@@ -31,6 +42,196 @@ impl From<transmuter_math::Decimal> for FFIDecimal {
     }
 }
 
+/// Parse a human-entered decimal string into a correctly-rounded 18-decimal
+/// `Decimal`, passed across the boundary as a NUL-terminated C string.
+///
+/// The parse is exact up to the 18th fractional digit and rounds the remaining
+/// tail half-to-even, propagating any carry into the integer part (so
+/// `0.999…` becomes `1.0`). Overflow and malformed input are surfaced through
+/// [`FFIResult::err`] instead of panicking across the boundary.
+#[no_mangle]
+pub extern "C" fn decimal_from_str(ptr: *const c_char) -> FFIResult<FFIDecimal> {
+    if ptr.is_null() {
+        return FFIResult::err("decimal_from_str: null pointer");
+    }
+
+    let input = match unsafe { CStr::from_ptr(ptr) }.to_str() {
+        Ok(input) => input,
+        Err(e) => return FFIResult::err(format!("decimal_from_str: invalid utf-8: {e}")),
+    };
+
+    match parse_decimal(input) {
+        Ok(decimal) => FFIResult::ok(decimal),
+        Err(e) => FFIResult::err(e),
+    }
+}
+
+/// Correctly-rounded fixed-point parse shared by the FFI entry point and tests.
+fn parse_decimal(input: &str) -> Result<FFIDecimal, String> {
+    let mut parts = input.splitn(2, '.');
+    let int_part = parts.next().unwrap_or("");
+    let frac_part = parts.next();
+
+    if int_part.is_empty() && frac_part.map(|f| f.is_empty()).unwrap_or(true) {
+        return Err(format!("decimal_from_str: empty decimal `{input}`"));
+    }
+
+    if !int_part.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!(
+            "decimal_from_str: invalid integer part in `{input}`"
+        ));
+    }
+
+    let integer = if int_part.is_empty() {
+        0u128
+    } else {
+        u128::from_str(int_part)
+            .map_err(|e| format!("decimal_from_str: integer part overflow in `{input}`: {e}"))?
+    };
+
+    let mut int_atomics = integer
+        .checked_mul(DECIMAL_FRACTIONAL)
+        .ok_or_else(|| format!("decimal_from_str: integer part too large in `{input}`"))?;
+
+    let mut frac_atomics = 0u128;
+    if let Some(frac) = frac_part {
+        if !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!(
+                "decimal_from_str: invalid fractional part in `{input}`"
+            ));
+        }
+
+        // Take the first 18 fractional digits, zero-padded on the right, as the
+        // base atomics. Parsing the whole slice at once avoids the rounding
+        // drift of a digit-by-digit multiply-accumulate.
+        let take = frac.len().min(DECIMAL_FRACTIONAL_DIGITS);
+        let mut base = String::with_capacity(DECIMAL_FRACTIONAL_DIGITS);
+        base.push_str(&frac[..take]);
+        while base.len() < DECIMAL_FRACTIONAL_DIGITS {
+            base.push('0');
+        }
+        frac_atomics = u128::from_str(&base)
+            .map_err(|e| format!("decimal_from_str: fractional part overflow in `{input}`: {e}"))?;
+
+        // Inspect the 19th digit onward to decide rounding, breaking exact ties
+        // toward the even last atomic.
+        if frac.len() > DECIMAL_FRACTIONAL_DIGITS {
+            let rest = frac[DECIMAL_FRACTIONAL_DIGITS..].as_bytes();
+            let first = rest[0] - b'0';
+            let round_up = match first.cmp(&5) {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => {
+                    if rest[1..].iter().any(|&b| b != b'0') {
+                        true
+                    } else {
+                        // Exact tie: round half to even.
+                        frac_atomics % 2 == 1
+                    }
+                }
+            };
+            if round_up {
+                frac_atomics += 1;
+            }
+        }
+    }
+
+    // A round-up past all-nines carries one whole unit into the integer part.
+    if frac_atomics >= DECIMAL_FRACTIONAL {
+        frac_atomics -= DECIMAL_FRACTIONAL;
+        int_atomics = int_atomics
+            .checked_add(DECIMAL_FRACTIONAL)
+            .ok_or_else(|| format!("decimal_from_str: overflow rounding `{input}`"))?;
+    }
+
+    let atomics = int_atomics
+        .checked_add(frac_atomics)
+        .ok_or_else(|| format!("decimal_from_str: overflow in `{input}`"))?;
+
+    Ok(transmuter_math::Decimal::raw(atomics).into())
+}
+
+/// Checked `Decimal` addition, surfacing overflow through [`FFIResult::err`]
+/// instead of panicking across the boundary.
+#[no_mangle]
+pub extern "C" fn ffi_decimal_checked_add(lhs: FFIDecimal, rhs: FFIDecimal) -> FFIResult<FFIDecimal> {
+    let lhs: transmuter_math::Decimal = lhs.into();
+    match lhs.checked_add(rhs.into()) {
+        Ok(result) => FFIResult::ok(result.into()),
+        Err(e) => FFIResult::err(e),
+    }
+}
+
+/// Checked `Decimal` subtraction; underflow is reported as an error.
+#[no_mangle]
+pub extern "C" fn ffi_decimal_checked_sub(lhs: FFIDecimal, rhs: FFIDecimal) -> FFIResult<FFIDecimal> {
+    let lhs: transmuter_math::Decimal = lhs.into();
+    match lhs.checked_sub(rhs.into()) {
+        Ok(result) => FFIResult::ok(result.into()),
+        Err(e) => FFIResult::err(e),
+    }
+}
+
+/// Checked `Decimal` multiplication; overflow is reported as an error.
+#[no_mangle]
+pub extern "C" fn ffi_decimal_checked_mul(lhs: FFIDecimal, rhs: FFIDecimal) -> FFIResult<FFIDecimal> {
+    let lhs: transmuter_math::Decimal = lhs.into();
+    match lhs.checked_mul(rhs.into()) {
+        Ok(result) => FFIResult::ok(result.into()),
+        Err(e) => FFIResult::err(e),
+    }
+}
+
+/// Checked `Decimal` division; division by zero is reported as an error.
+#[no_mangle]
+pub extern "C" fn ffi_decimal_checked_div(lhs: FFIDecimal, rhs: FFIDecimal) -> FFIResult<FFIDecimal> {
+    let lhs: transmuter_math::Decimal = lhs.into();
+    match lhs.checked_div(rhs.into()) {
+        Ok(result) => FFIResult::ok(result.into()),
+        Err(e) => FFIResult::err(e),
+    }
+}
+
+/// Checked `u128` addition, surfacing overflow through [`FFIResult::err`].
+#[no_mangle]
+pub extern "C" fn ffi_u128_checked_add(lhs: FFIU128, rhs: FFIU128) -> FFIResult<FFIU128> {
+    let lhs: u128 = lhs.into();
+    match lhs.checked_add(rhs.into()) {
+        Some(result) => FFIResult::ok(result.into()),
+        None => FFIResult::err("u128 addition overflow"),
+    }
+}
+
+/// Checked `u128` subtraction; underflow is reported as an error.
+#[no_mangle]
+pub extern "C" fn ffi_u128_checked_sub(lhs: FFIU128, rhs: FFIU128) -> FFIResult<FFIU128> {
+    let lhs: u128 = lhs.into();
+    match lhs.checked_sub(rhs.into()) {
+        Some(result) => FFIResult::ok(result.into()),
+        None => FFIResult::err("u128 subtraction underflow"),
+    }
+}
+
+/// Checked `u128` multiplication; overflow is reported as an error.
+#[no_mangle]
+pub extern "C" fn ffi_u128_checked_mul(lhs: FFIU128, rhs: FFIU128) -> FFIResult<FFIU128> {
+    let lhs: u128 = lhs.into();
+    match lhs.checked_mul(rhs.into()) {
+        Some(result) => FFIResult::ok(result.into()),
+        None => FFIResult::err("u128 multiplication overflow"),
+    }
+}
+
+/// Checked `u128` division; division by zero is reported as an error.
+#[no_mangle]
+pub extern "C" fn ffi_u128_checked_div(lhs: FFIU128, rhs: FFIU128) -> FFIResult<FFIU128> {
+    let lhs: u128 = lhs.into();
+    match lhs.checked_div(rhs.into()) {
+        Some(result) => FFIResult::ok(result.into()),
+        None => FFIResult::err("u128 division by zero"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +293,47 @@ mod tests {
         let ffi_fractional = FFIDecimal::from(fractional);
         assert_eq!(Decimal::from(ffi_fractional), fractional);
     }
+
+    fn atomics(input: &str) -> u128 {
+        transmuter_math::Decimal::from(parse_decimal(input).unwrap())
+            .atomics()
+            .u128()
+    }
+
+    #[test]
+    fn test_parse_decimal_exact() {
+        assert_eq!(atomics("0"), 0);
+        assert_eq!(atomics("1"), 1_000_000_000_000_000_000);
+        assert_eq!(atomics("1.5"), 1_500_000_000_000_000_000);
+        assert_eq!(atomics("0.000000000000000001"), 1);
+        // 18-digit fraction is taken verbatim, no rounding.
+        assert_eq!(atomics("0.123456789012345678"), 123_456_789_012_345_678);
+    }
+
+    #[test]
+    fn test_parse_decimal_half_to_even() {
+        // Just below the 19th-digit tie rounds down.
+        assert_eq!(atomics("0.9687499999999999994"), 968_749_999_999_999_999);
+        // Exact tie with an even last atomic stays put.
+        assert_eq!(atomics("0.0000000000000000005"), 0);
+        // Exact tie with an odd last atomic rounds up to even.
+        assert_eq!(atomics("0.0000000000000000015"), 2);
+        // Past the tie rounds up.
+        assert_eq!(atomics("0.0000000000000000006"), 1);
+    }
+
+    #[test]
+    fn test_parse_decimal_carry() {
+        assert_eq!(atomics("0.9999999999999999999"), 1_000_000_000_000_000_000);
+        assert_eq!(atomics("1.9999999999999999999"), 2_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_parse_decimal_rejects_malformed() {
+        assert!(parse_decimal("").is_err());
+        assert!(parse_decimal("-1.0").is_err());
+        assert!(parse_decimal("1.2.3").is_err());
+        assert!(parse_decimal("1.2a").is_err());
+        assert!(parse_decimal("abc").is_err());
+    }
 }
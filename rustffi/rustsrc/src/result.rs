@@ -1,4 +1,13 @@
-use std::{ffi::CString, fmt::Display};
+use std::{
+    ffi::{c_char, CString},
+    fmt::Display,
+};
+
+use crate::{
+    numbers::{FFIDecimal, FFIU128},
+    slice::FFISlice,
+    transmuter::{FFICleanUpResult, FFIDivision},
+};
 
 #[repr(C)]
 pub struct FFIResult<T> {
@@ -25,6 +34,91 @@ impl<T> FFIResult<T> {
     }
 }
 
+impl<T> FFIResult<T> {
+    /// Reclaim the memory owned by this result, dropping either the boxed `ok`
+    /// payload or the `err` C string. Exactly one of the two pointers is
+    /// non-null for a well-formed result; each is guarded against null.
+    fn free(self) {
+        if !self.ok.is_null() {
+            drop(unsafe { Box::from_raw(self.ok as *mut T) });
+        }
+        if !self.err.is_null() {
+            drop(unsafe { CString::from_raw(self.err as *mut c_char) });
+        }
+    }
+}
+
+/// Reclaim an `FFIResult<FFIDecimal>`.
+///
+/// # Ownership
+///
+/// Every `FFIResult` returned across the boundary owns its heap allocation
+/// (the boxed `ok` value or the `err` string). The caller must pass each
+/// returned result to exactly one matching `free_ffi_result_*` destructor and
+/// must not use it afterwards.
+#[no_mangle]
+pub extern "C" fn free_ffi_result_decimal(res: FFIResult<FFIDecimal>) {
+    res.free();
+}
+
+/// Reclaim an `FFIResult<u8>`. See [`free_ffi_result_decimal`] for the
+/// ownership contract.
+#[no_mangle]
+pub extern "C" fn free_ffi_result_u8(res: FFIResult<u8>) {
+    res.free();
+}
+
+/// Reclaim an `FFIResult<FFIU128>` as returned by the `ffi_u128_checked_*`
+/// arithmetic wrappers. See [`free_ffi_result_decimal`] for the ownership
+/// contract.
+#[no_mangle]
+pub extern "C" fn free_ffi_result_u128(res: FFIResult<FFIU128>) {
+    res.free();
+}
+
+/// Reclaim the heap-allocated division array of an `FFISlice<FFIDivision>`,
+/// exactly once; a null slice is a no-op. The `divisions` slice of an
+/// `FFICleanUpResult` is already reclaimed by [`free_ffi_result_clean_up`];
+/// passing it here as well frees it twice.
+#[no_mangle]
+pub extern "C" fn free_ffi_slice_division(slice: FFISlice<FFIDivision>) {
+    slice.free();
+}
+
+/// Reclaim an `FFIResult<FFISlice<FFIDecimal>>` as returned by
+/// `compressed_moving_average_batch`. On `err` the C string is freed; on `ok`
+/// both the heap `FFISlice` box and its backing element buffer are freed. See
+/// [`free_ffi_result_decimal`] for the one-free-per-result contract.
+#[no_mangle]
+pub extern "C" fn free_ffi_result_slice_decimal(res: FFIResult<FFISlice<FFIDecimal>>) {
+    if !res.ok.is_null() {
+        let slice = *unsafe { Box::from_raw(res.ok as *mut FFISlice<FFIDecimal>) };
+        slice.free();
+    }
+    if !res.err.is_null() {
+        drop(unsafe { CString::from_raw(res.err as *mut c_char) });
+    }
+}
+
+/// Reclaim an `FFIResult<FFICleanUpResult>` as returned by
+/// `clean_up_outdated_divisions`. On `err` the C string is freed; on `ok` the
+/// heap `FFICleanUpResult` box, the surviving-division buffer, and the boxed
+/// latest-removed division (when non-null) are all freed. See
+/// [`free_ffi_result_decimal`] for the one-free-per-result contract.
+#[no_mangle]
+pub extern "C" fn free_ffi_result_clean_up(res: FFIResult<FFICleanUpResult>) {
+    if !res.ok.is_null() {
+        let result = *unsafe { Box::from_raw(res.ok as *mut FFICleanUpResult) };
+        result.divisions.free();
+        if !result.latest_removed_division.is_null() {
+            drop(unsafe { Box::from_raw(result.latest_removed_division as *mut FFIDivision) });
+        }
+    }
+    if !res.err.is_null() {
+        drop(unsafe { CString::from_raw(res.err as *mut c_char) });
+    }
+}
+
 impl<T, E: Display> From<Result<T, E>> for FFIResult<T> {
     fn from(value: Result<T, E>) -> Self {
         match value {
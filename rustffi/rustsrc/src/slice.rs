@@ -9,4 +9,24 @@ impl<T> FFISlice<T> {
     pub fn as_slice(&self) -> &[T] {
         unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
     }
+
+    /// Heap-allocate the vector's buffer and hand its ownership to the caller.
+    /// The backing allocation must later be reclaimed through
+    /// `free_ffi_slice_division` or through the `free_ffi_result_*` destructor
+    /// of the result that carries the slice.
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let boxed = vec.into_boxed_slice();
+        let len = boxed.len();
+        let ptr = Box::into_raw(boxed) as *const T;
+        FFISlice { ptr, len }
+    }
+
+    /// Reclaim the buffer produced by [`FFISlice::from_vec`], dropping its
+    /// elements. A null pointer is treated as an already-freed slice.
+    pub fn free(self) {
+        if !self.ptr.is_null() {
+            let raw = std::ptr::slice_from_raw_parts_mut(self.ptr as *mut T, self.len);
+            drop(unsafe { Box::from_raw(raw) });
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{numbers::FFIDecimal, result::FFIResult};
+use crate::{numbers::FFIDecimal, result::FFIResult, slice::FFISlice};
 use transmuter_math::{Division, Timestamp, Uint64};
 
 #[repr(C)]
@@ -28,6 +28,27 @@ impl FFIDivision {
     }
 }
 
+impl From<Division> for FFIDivision {
+    fn from(value: Division) -> Self {
+        FFIDivision {
+            started_at: value.started_at().nanos(),
+            updated_at: value.updated_at().nanos(),
+            latest_value: value.latest_value().into(),
+            integral: value.integral().into(),
+        }
+    }
+}
+
+/// Result of [`clean_up_outdated_divisions`]: the surviving divisions plus the
+/// most-recently pruned division, which the moving-average functions require as
+/// their `latest_removed_division` input. `latest_removed_division` is null when
+/// nothing was pruned, mirroring the nullable pointer those functions accept.
+#[repr(C)]
+pub struct FFICleanUpResult {
+    pub divisions: FFISlice<FFIDivision>,
+    pub latest_removed_division: *const FFIDivision,
+}
+
 fn ptr_to_option<T: Clone>(ptr: *const T) -> Option<T> {
     if ptr.is_null() {
         None
@@ -65,4 +86,80 @@ pub extern "C" fn compressed_moving_average(
     }
 }
 
-// TODO: expose `clean_up_outdated_divisions`
+#[no_mangle]
+pub extern "C" fn compressed_moving_average_batch(
+    latest_removed_division: *const FFIDivision,
+    divisions_ptr: *const FFIDivision,
+    divisions_len: usize,
+    division_size: u64,
+    window_size: u64,
+    block_times_ptr: *const u64, // timestamp nanos
+    block_times_len: usize,
+) -> FFIResult<FFISlice<FFIDecimal>> {
+    // Convert the divisions once and reuse them across every timestamp so the
+    // conversion cost is amortized over the whole series.
+    let latest_removed_division = ptr_to_option(latest_removed_division).map(|d| d.into_division());
+    let divisions = unsafe { std::slice::from_raw_parts(divisions_ptr, divisions_len) }
+        .iter()
+        .cloned()
+        .map(|d| d.into_division())
+        .collect::<Vec<_>>();
+    let block_times = unsafe { std::slice::from_raw_parts(block_times_ptr, block_times_len) };
+
+    let mut results = Vec::with_capacity(block_times.len());
+    for (index, &block_time) in block_times.iter().enumerate() {
+        let res = transmuter_math::compressed_moving_average(
+            latest_removed_division.clone(),
+            divisions.as_slice(),
+            Uint64::from(division_size),
+            Uint64::from(window_size),
+            Timestamp::from_nanos(block_time),
+        );
+        match res {
+            Ok(decimal) => results.push(FFIDecimal::from(decimal)),
+            Err(e) => return FFIResult::err(format!("block time at index {index}: {e}")),
+        }
+    }
+
+    FFIResult::ok(FFISlice::from_vec(results))
+}
+
+#[no_mangle]
+pub extern "C" fn clean_up_outdated_divisions(
+    divisions_ptr: *const FFIDivision,
+    divisions_len: usize,
+    division_size: u64,
+    window_size: u64,
+    block_time: u64, // timestamp nanos
+) -> FFIResult<FFICleanUpResult> {
+    let divisions = unsafe { std::slice::from_raw_parts(divisions_ptr, divisions_len) }
+        .iter()
+        .cloned()
+        .map(|d| d.into_division())
+        .collect::<Vec<_>>();
+
+    let res = transmuter_math::clean_up_outdated_divisions(
+        divisions.as_slice(),
+        Uint64::from(division_size),
+        Uint64::from(window_size),
+        Timestamp::from_nanos(block_time),
+    );
+
+    match res {
+        Ok((latest_removed_division, divisions)) => {
+            let surviving = divisions.into_iter().map(FFIDivision::from).collect::<Vec<_>>();
+            // Hand the removed division back alongside the survivors so the Go
+            // caller can feed it straight into `compressed_moving_average`
+            // without reimplementing the pruning rule; null signals "none".
+            let latest_removed_division = match latest_removed_division {
+                Some(d) => Box::into_raw(Box::new(FFIDivision::from(d))) as *const FFIDivision,
+                None => std::ptr::null(),
+            };
+            FFIResult::ok(FFICleanUpResult {
+                divisions: FFISlice::from_vec(surviving),
+                latest_removed_division,
+            })
+        }
+        Err(e) => FFIResult::err(e),
+    }
+}